@@ -0,0 +1,34 @@
+use crate::error::Error;
+use crate::retry::RetryPolicy;
+
+/// Sends a request via `send`, retrying per `policy` when an attempt fails
+/// with a transient error and `method` is eligible: classify the error with
+/// [`RetryPolicy::is_retryable`], sleep [`RetryPolicy::delay_for`], and
+/// re-send. On exhaustion, or when the error/method isn't retryable, the
+/// last mapped exception is returned unchanged so existing `except` clauses
+/// still work.
+pub(crate) async fn send_with_retry<F, Fut>(
+    policy: &RetryPolicy,
+    method: &rquest::Method,
+    mut send: F,
+) -> Result<rquest::Response, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<rquest::Response, (rquest::Error, Option<rquest::header::HeaderMap>)>>,
+{
+    let mut attempt = 0u32;
+    loop {
+        attempt += 1;
+        match send().await {
+            Ok(response) => return Ok(response),
+            Err((error, headers)) => {
+                let retryable = policy.allows_method(method) && policy.is_retryable(&error);
+                if !retryable || attempt >= policy.max_attempts {
+                    return Err(Error::from(error));
+                }
+                let delay = policy.delay_for(attempt, headers.as_ref());
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
@@ -1,23 +1,10 @@
 use pyo3::{
-    PyErr, create_exception,
+    PyErr, Python, create_exception,
     exceptions::{PyException, PyRuntimeError, PyStopAsyncIteration, PyStopIteration},
+    types::PyAnyMethods,
 };
 use rquest::header;
 
-const RACE_CONDITION_ERROR_MSG: &str = r#"Due to Rust's memory management with borrowing,
-you cannot use certain instances multiple times as they may be consumed.
-
-This error can occur in the following cases:
-1) You passed a non-clonable instance to a function that requires ownership.
-2) You attempted to use a method that consumes ownership more than once (e.g., reading a response body twice).
-3) You tried to reference an instance after it was borrowed.
-
-Potential solutions:
-1) Avoid sharing instances; create a new instance each time you use it.
-2) Refrain from performing actions that consume ownership multiple times.
-3) Change the order of operations to reference the instance before borrowing it.
-"#;
-
 create_exception!(exceptions, BorrowingError, PyRuntimeError);
 create_exception!(exceptions, DNSResolverError, PyRuntimeError);
 
@@ -31,31 +18,100 @@ create_exception!(exceptions, TimeoutError, BaseError);
 create_exception!(exceptions, StatusError, BaseError);
 create_exception!(exceptions, RequestError, BaseError);
 create_exception!(exceptions, UnknownError, BaseError);
+create_exception!(exceptions, WebSocketDisconnect, BaseError);
 
 create_exception!(exceptions, HTTPMethodParseError, PyException);
 create_exception!(exceptions, URLParseError, PyException);
 create_exception!(exceptions, MIMEParseError, PyException);
 
+create_exception!(exceptions, ContentLimitError, BaseError);
+
+/// Default maximum size, in bytes, of a single response body before
+/// [`Error::BodyLimitExceeded`] is raised. Applies whether the limit is
+/// crossed via an advertised `Content-Length` or by accumulating streamed
+/// chunks, guarding callers against unbounded memory growth on hostile or
+/// buggy servers. `ResponseBody::with_limits` takes another cap for one
+/// response; there is no per-client setting, and `dispatch` always uses
+/// this default.
+pub(crate) const DEFAULT_MAX_BODY_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Default wall-clock deadline for reading a response body to completion,
+/// overridable alongside [`DEFAULT_MAX_BODY_SIZE`] in the same way.
+pub(crate) const DEFAULT_BODY_READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 macro_rules! wrap_error {
     ($error:expr, $($variant:ident => $exception:ident),*) => {
         {
             $(
                 if $error.$variant() {
-                    return $exception::new_err(format!(concat!(stringify!($variant), " error: {:?}"), $error));
+                    let message = format!(concat!(stringify!($variant), " error: {:?}"), $error);
+                    return attach_http_context($exception::new_err(message), &$error);
                 }
             )*
-            UnknownError::new_err(format!("Unknown error occurred: {:?}", $error))
+            attach_http_context(UnknownError::new_err(format!("Unknown error occurred: {:?}", $error)), &$error)
         }
     };
 }
 
+/// Populate `.status`, `.url`, and `.headers` on a just-raised exception so
+/// Python callers can branch on them (e.g. `except StatusError as e: if
+/// e.status == 429: ...`) instead of parsing the message string.
+///
+/// `.headers` is always `None`: `rquest::Error` carries no response
+/// headers, and `send_with_retry` does not pass on the headers it receives
+/// with a failed attempt.
+fn attach_http_context(err: PyErr, source: &rquest::Error) -> PyErr {
+    Python::with_gil(|py| {
+        let value = err.value(py);
+        let _ = value.setattr("status", source.status().map(|status| status.as_u16()));
+        let _ = value.setattr("url", source.url().map(ToString::to_string));
+        let _ = value.setattr("headers", py.None());
+    });
+    attach_cause(err, source)
+}
+
+/// Chain `err`'s `__cause__` through one synthetic `BaseError` per link in
+/// the underlying error's `source()` chain, innermost transport/TLS error
+/// last, the way `raise X from Y` would. This keeps each link distinct
+/// instead of collapsing the whole chain into one string.
+fn attach_cause(err: PyErr, source: &rquest::Error) -> PyErr {
+    let mut links = Vec::new();
+    let mut cur = std::error::Error::source(source);
+    while let Some(link) = cur {
+        links.push(link.to_string());
+        cur = link.source();
+    }
+
+    let mut cause: Option<PyErr> = None;
+    for link in links.into_iter().rev() {
+        let exc = BaseError::new_err(link);
+        Python::with_gil(|py| exc.set_cause(py, cause.take()));
+        cause = Some(exc);
+    }
+    Python::with_gil(|py| err.set_cause(py, cause));
+    err
+}
+
 /// Unified error enum
 #[derive(Debug)]
 pub enum Error {
-    MemoryError,
+    /// Raised when a use-once instance is used again. `ResponseBody::bytes`
+    /// is the only guard that raises it; streaming readers and request
+    /// builders have no such guard in this crate.
+    AlreadyConsumed {
+        type_name: &'static str,
+        op: &'static str,
+    },
     StopIteration,
     StopAsyncIteration,
-    WebSocketDisconnect,
+    /// Carries the RFC 6455 close code and optional reason from the
+    /// WebSocket's closing frame, if one was received before the stream
+    /// ended.
+    WebSocketDisconnect { code: u16, reason: Option<String> },
+    /// Raised when a response body's advertised `Content-Length` or
+    /// accumulated streamed size crosses the configured cap. A read that
+    /// runs past its deadline is reported as a `TimedOut` [`Error::IoError`].
+    BodyLimitExceeded { limit: u64, seen: u64 },
     InvalidHeaderName(header::InvalidHeaderName),
     InvalidHeaderValue(header::InvalidHeaderValue),
     UrlParseError(url::ParseError),
@@ -66,11 +122,42 @@ pub enum Error {
 impl From<Error> for PyErr {
     fn from(err: Error) -> Self {
         match err {
-            Error::MemoryError => PyRuntimeError::new_err(RACE_CONDITION_ERROR_MSG),
+            Error::AlreadyConsumed { type_name, op } => {
+                let err = BorrowingError::new_err(format!(
+                    "{type_name}.{op}() called after the instance was already consumed"
+                ));
+                Python::with_gil(|py| {
+                    let value = err.value(py);
+                    let _ = value.setattr("type_name", type_name);
+                    let _ = value.setattr("operation", op);
+                });
+                err
+            }
             Error::StopIteration => PyStopIteration::new_err("The iterator is exhausted"),
             Error::StopAsyncIteration => PyStopAsyncIteration::new_err("The iterator is exhausted"),
-            Error::WebSocketDisconnect => {
-                PyRuntimeError::new_err("The WebSocket has been disconnected")
+            Error::WebSocketDisconnect { code, reason } => {
+                let message = match &reason {
+                    Some(reason) => format!("The WebSocket was closed with code {code}: {reason}"),
+                    None => format!("The WebSocket was closed with code {code}"),
+                };
+                let err = WebSocketDisconnect::new_err(message);
+                Python::with_gil(|py| {
+                    let value = err.value(py);
+                    let _ = value.setattr("code", code);
+                    let _ = value.setattr("reason", reason);
+                });
+                err
+            }
+            Error::BodyLimitExceeded { limit, seen } => {
+                let err = ContentLimitError::new_err(format!(
+                    "response body exceeded the configured limit: {seen} bytes read (limit is {limit} bytes)"
+                ));
+                Python::with_gil(|py| {
+                    let value = err.value(py);
+                    let _ = value.setattr("limit", limit);
+                    let _ = value.setattr("seen", seen);
+                });
+                err
             }
             Error::InvalidHeaderName(err) => {
                 PyRuntimeError::new_err(format!("Invalid header name: {:?}", err))
@@ -0,0 +1,38 @@
+//! Crate root: declares the submodules and wires them into the shared
+//! request-dispatch helpers below.
+
+mod client;
+mod error;
+mod response;
+mod retry;
+mod ws;
+
+pub(crate) use error::Error;
+pub(crate) use retry::RetryPolicy;
+
+/// Sends a request end-to-end: retries per `policy`, then reads the
+/// resulting body under the default size/time guard.
+pub(crate) async fn dispatch<F, Fut>(
+    policy: &RetryPolicy,
+    method: rquest::Method,
+    send: F,
+) -> Result<Vec<u8>, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<
+        Output = Result<rquest::Response, (rquest::Error, Option<rquest::header::HeaderMap>)>,
+    >,
+{
+    let response = client::send_with_retry(policy, &method, send).await?;
+    response::ResponseBody::new(response).bytes().await
+}
+
+/// Maps how a websocket stream ended into our error type: `Some((code,
+/// reason))` for a real close frame, `None` when the stream just stopped
+/// producing frames.
+pub(crate) fn ws_close_error(frame: Option<(u16, Option<String>)>) -> Error {
+    match frame {
+        Some((code, reason)) => ws::disconnect_error(code, reason),
+        None => ws::disconnect_without_frame(),
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::{Duration, Instant};
+
+use crate::error::{DEFAULT_BODY_READ_TIMEOUT, DEFAULT_MAX_BODY_SIZE, Error};
+
+/// Wraps a `rquest::Response`, guarding its body against being read more
+/// than once. Rust's ownership rules would normally make a double read a
+/// compile error; once the response crosses into Python that guarantee is
+/// gone, so this re-creates it at runtime as a plain `Option::take`: the
+/// first `bytes()` call consumes the response, and every call after that
+/// raises [`Error::AlreadyConsumed`]. There's no separate "currently
+/// borrowed" state here — only "already consumed" or not.
+pub(crate) struct ResponseBody {
+    inner: Option<rquest::Response>,
+    max_size: u64,
+    timeout: Duration,
+}
+
+impl ResponseBody {
+    pub(crate) fn new(response: rquest::Response) -> Self {
+        Self::with_limits(response, DEFAULT_MAX_BODY_SIZE, DEFAULT_BODY_READ_TIMEOUT)
+    }
+
+    pub(crate) fn with_limits(response: rquest::Response, max_size: u64, timeout: Duration) -> Self {
+        Self {
+            inner: Some(response),
+            max_size,
+            timeout,
+        }
+    }
+
+    /// Reads the whole body, rejecting early if the advertised
+    /// `Content-Length` exceeds `max_size` and aborting mid-stream once
+    /// accumulated bytes cross it, so chunked/streamed responses are bounded
+    /// too ([`Error::BodyLimitExceeded`]). Once `timeout` elapses, even while
+    /// waiting on a chunk, it fails with a `TimedOut` [`Error::IoError`].
+    /// Raises [`Error::AlreadyConsumed`] if the body was already read.
+    pub(crate) async fn bytes(&mut self) -> Result<Vec<u8>, Error> {
+        let mut response = self.inner.take().ok_or(Error::AlreadyConsumed {
+            type_name: "Response",
+            op: "bytes",
+        })?;
+
+        if let Some(len) = response.content_length() {
+            if len > self.max_size {
+                return Err(Error::BodyLimitExceeded {
+                    limit: self.max_size,
+                    seen: len,
+                });
+            }
+        }
+
+        let deadline = tokio::time::Instant::now() + self.timeout;
+        let mut body = Vec::new();
+        loop {
+            let chunk = tokio::time::timeout_at(deadline, response.chunk())
+                .await
+                .map_err(|_| {
+                    Error::IoError(std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        "response body read deadline elapsed",
+                    ))
+                })??;
+            let Some(chunk) = chunk else { break };
+            body.extend_from_slice(&chunk);
+            let seen = body.len() as u64;
+            if seen > self.max_size {
+                return Err(Error::BodyLimitExceeded {
+                    limit: self.max_size,
+                    seen,
+                });
+            }
+        }
+        Ok(body)
+    }
+}
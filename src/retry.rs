@@ -0,0 +1,232 @@
+use std::cell::Cell;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rquest::{Method, StatusCode, header::HeaderMap};
+
+/// Retry policy consumed by the client when scheduling re-attempts of a
+/// failed request. Built from the same predicate dispatch `wrap_error!`
+/// already uses to classify `rquest::Error` (`is_timeout`, `is_connect`,
+/// `is_status`, ...), so a request only retries when the failure looks
+/// transient.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+    /// POST isn't idempotent, so by default it isn't retried; callers can
+    /// opt in explicitly.
+    pub retry_post: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retry_post: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether `method` is eligible for retry under this policy. Only
+    /// idempotent methods retry by default.
+    pub fn allows_method(&self, method: &Method) -> bool {
+        match *method {
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS => true,
+            Method::POST => self.retry_post,
+            _ => false,
+        }
+    }
+
+    /// Classifies whether `error` is worth retrying: connect/reset/timeout
+    /// failures and 502/503/504/429 responses are retryable; builder,
+    /// redirect, and other 4xx errors are not.
+    pub fn is_retryable(&self, error: &rquest::Error) -> bool {
+        if error.is_connect() || error.is_connection_reset() || error.is_timeout() {
+            return true;
+        }
+        error.status().is_some_and(|status| {
+            matches!(
+                status,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            )
+        })
+    }
+
+    /// Delay before attempt number `attempt` (1-indexed). Prefers a
+    /// `Retry-After` header over the computed exponential backoff, which is
+    /// `min(max_delay, base_delay * 2^(attempt-1))` with full jitter applied
+    /// when `jitter` is set.
+    pub fn delay_for(&self, attempt: u32, response_headers: Option<&HeaderMap>) -> Duration {
+        if let Some(delay) = response_headers.and_then(retry_after_delay) {
+            return delay;
+        }
+        let exponent = attempt.saturating_sub(1).min(32);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1u32.checked_shl(exponent).unwrap_or(u32::MAX));
+        let capped = backoff.min(self.max_delay);
+        if self.jitter { full_jitter(capped) } else { capped }
+    }
+}
+
+/// `uniform(0, delay)`, i.e. AWS's "full jitter" backoff strategy.
+fn full_jitter(delay: Duration) -> Duration {
+    let millis = delay.as_millis().min(u128::from(u64::MAX)) as u64;
+    if millis == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_millis(next_random_u64() % (millis + 1))
+}
+
+thread_local! {
+    static RNG_STATE: Cell<u64> = Cell::new(seed());
+}
+
+fn seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0x9E37_79B9_7F4A_7C15);
+    nanos | 1
+}
+
+/// A splitmix64 step. Good enough for jitter spread, not for anything that
+/// needs real randomness.
+fn next_random_u64() -> u64 {
+    RNG_STATE.with(|state| {
+        let mut x = state.get().wrapping_add(0x9E37_79B9_7F4A_7C15);
+        state.set(x);
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        x ^ (x >> 31)
+    })
+}
+
+/// Parses a `Retry-After` header, supporting both the delta-seconds and
+/// HTTP-date forms from RFC 7231 §7.1.3.
+fn retry_after_delay(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(rquest::header::RETRY_AFTER)?.to_str().ok()?;
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    parse_http_date(value)
+}
+
+fn parse_http_date(value: &str) -> Option<Duration> {
+    // e.g. "Wed, 21 Oct 2015 07:28:00 GMT"
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    let [_, day, month, year, time, _] = parts[..] else {
+        return None;
+    };
+    let day: u64 = day.parse().ok()?;
+    let month = match month {
+        "Jan" => 1,
+        "Feb" => 2,
+        "Mar" => 3,
+        "Apr" => 4,
+        "May" => 5,
+        "Jun" => 6,
+        "Jul" => 7,
+        "Aug" => 8,
+        "Sep" => 9,
+        "Oct" => 10,
+        "Nov" => 11,
+        "Dec" => 12,
+        _ => return None,
+    };
+    let year: u64 = year.parse().ok()?;
+    let mut time_parts = time.split(':');
+    let hour: u64 = time_parts.next()?.parse().ok()?;
+    let min: u64 = time_parts.next()?.parse().ok()?;
+    let sec: u64 = time_parts.next()?.parse().ok()?;
+
+    let target_secs =
+        days_from_civil(year, month, day) * 86_400 + hour * 3_600 + min * 60 + sec;
+    let now_secs = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    target_secs.checked_sub(now_secs).map(Duration::from_secs)
+}
+
+/// Days since the Unix epoch for a civil date, per Howard Hinnant's
+/// `days_from_civil` algorithm.
+fn days_from_civil(y: u64, m: u64, d: u64) -> u64 {
+    let y = if m <= 2 { y as i64 - 1 } else { y as i64 };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as u64;
+    let mp = (m + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    (era * 146_097 + doe as i64 - 719_468) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(jitter: bool) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            jitter,
+            retry_post: false,
+        }
+    }
+
+    #[test]
+    fn delay_for_grows_exponentially_until_the_cap() {
+        let policy = policy(false);
+        assert_eq!(policy.delay_for(1, None), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(2, None), Duration::from_millis(400));
+        assert_eq!(policy.delay_for(3, None), Duration::from_millis(800));
+        assert_eq!(policy.delay_for(4, None), Duration::from_millis(1600));
+        // base_delay * 2^6 would be 12.8s, above max_delay, so it caps at 10s.
+        assert_eq!(policy.delay_for(7, None), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn delay_for_full_jitter_stays_within_bounds() {
+        let without_jitter = policy(false);
+        let with_jitter = policy(true);
+        for attempt in 1..8 {
+            let capped = without_jitter.delay_for(attempt, None);
+            let delay = with_jitter.delay_for(attempt, None);
+            assert!(delay <= capped, "attempt {attempt}: {delay:?} > {capped:?}");
+        }
+    }
+
+    #[test]
+    fn delay_for_prefers_retry_after_delta_seconds() {
+        let policy = policy(false);
+        let mut headers = HeaderMap::new();
+        headers.insert(rquest::header::RETRY_AFTER, "120".parse().unwrap());
+        assert_eq!(policy.delay_for(1, Some(&headers)), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn days_from_civil_matches_known_epoch_offsets() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(2015, 10, 21), 16_729);
+    }
+
+    #[test]
+    fn parse_http_date_rejects_malformed_input() {
+        assert_eq!(parse_http_date("not a date"), None);
+    }
+
+    #[test]
+    fn parse_http_date_returns_none_for_an_already_past_date() {
+        // A well-formed but already-elapsed date can't yield a positive
+        // delay, so it's treated the same as a missing header (falls back
+        // to the computed exponential backoff).
+        assert_eq!(parse_http_date("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+    }
+}
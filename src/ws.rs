@@ -0,0 +1,17 @@
+use crate::error::Error;
+
+/// Maps a websocket closing frame into [`Error::WebSocketDisconnect`],
+/// capturing the RFC 6455 status code and reason so Python callers can
+/// distinguish a normal 1000 close from a 1006 abnormal closure or a server
+/// policy 1008/1011 without parsing strings. Called by
+/// [`crate::ws_close_error`] when a close frame was received.
+pub(crate) fn disconnect_error(code: u16, reason: Option<String>) -> Error {
+    Error::WebSocketDisconnect { code, reason }
+}
+
+/// The stream ended without ever receiving a close frame (e.g. the
+/// underlying connection dropped), which RFC 6455 treats as an abnormal
+/// closure.
+pub(crate) fn disconnect_without_frame() -> Error {
+    disconnect_error(1006, None)
+}